@@ -49,7 +49,6 @@ fn depth_first_search<T: Eq>(tree: &Node<T>, searched: &T) -> bool {
 #[cfg(test)]
 mod tests {
     use crate::basic_search;
-    use crate::tree;
 
     #[test]
     fn dfs() {
@@ -2,7 +2,13 @@
 
 mod basic_search;
 
-pub use mcts::find_next_move;
+use rand::Rng;
+
+pub use mcts::{
+    find_next_move, find_next_move_parallel, find_next_move_seeded,
+    find_next_move_seeded_with_exploration, find_next_move_timed, find_next_move_with_tree_dump,
+    TreeDumpFormat,
+};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Status<P> {
@@ -25,125 +31,518 @@ pub trait GameState: Clone + std::fmt::Debug {
 
     fn toggle_player(&mut self);
 
-    fn next_random_play(&mut self);
+    fn next_random_play(&mut self, rng: &mut impl Rng);
 }
 
 mod mcts {
     use crate::{GameState, PlayerState, Status};
     use bumpalo::Bump;
-    use rand::Rng;
+    use ordered_float::OrderedFloat;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
     use std::cell::Cell;
 
-    #[derive(Debug, Clone)]
+    #[derive(Debug)]
     struct Node<'tree, S: GameState> {
         state: S,
         // todo: don't have this field and let the GameState handle this all
         player: S::Player,
-        visited: Cell<u32>,
-        score: Cell<i32>,
+        visits: Cell<u32>,
+        wins: Cell<f64>,
+        losses: Cell<f64>,
         parent: Option<&'tree Node<'tree, S>>,
-        children: Cell<&'tree [Node<'tree, S>]>,
+        // Children form an intrusive singly-linked list through
+        // `next_sibling` instead of a contiguous slice, so that expanding a
+        // new child is a single bump allocation that never touches its
+        // siblings, however many of them have already been expanded.
+        first_child: Cell<Option<&'tree Node<'tree, S>>>,
+        next_sibling: Cell<Option<&'tree Node<'tree, S>>>,
+        // Successor states that haven't been turned into a child `Node`
+        // yet. A node is only considered for UCB1 selection among its
+        // children once this is empty; until then, each visit expands
+        // exactly one more child from here instead of all of them at once.
+        unexplored: Cell<&'tree [S]>,
     }
 
     impl<'tree, S: GameState> Node<'tree, S> {
-        fn new(state: S, player: S::Player, alloc: &'tree Bump) -> Node<'tree, S> {
+        fn new(
+            state: S,
+            player: S::Player,
+            parent: Option<&'tree Node<'tree, S>>,
+            alloc: &'tree Bump,
+        ) -> Node<'tree, S> {
+            // A finished game (even one with free fields left, e.g. a won
+            // board) has no real successors; populating `unexplored` from
+            // `next_states()` regardless would let expansion play phantom
+            // moves after the result is already decided.
+            let unexplored: &[S] = if state.status() == Status::InProgress {
+                alloc.alloc_slice_fill_iter(state.next_states())
+            } else {
+                &[]
+            };
+
             Self {
                 state,
                 player,
-                visited: Cell::new(0),
-                score: Cell::new(0),
-                parent: None,
-                children: Cell::new(alloc.alloc([])),
+                visits: Cell::new(0),
+                wins: Cell::new(0.0),
+                losses: Cell::new(0.0),
+                parent,
+                first_child: Cell::new(None),
+                next_sibling: Cell::new(None),
+                unexplored: Cell::new(unexplored),
             }
         }
 
-        fn random_child(&self) -> &Self {
-            let children = self.children.get();
-            let random_index = rand::thread_rng().gen_range(0..children.len());
+        /// Takes the next not-yet-expanded successor state, if any, leaving
+        /// the rest for later visits.
+        fn pop_unexplored(&self) -> Option<S> {
+            let (first, rest) = self.unexplored.get().split_first()?;
+            self.unexplored.set(rest);
+            Some(first.clone())
+        }
 
-            &children[random_index]
+        /// Prepends `child` to this node's children in O(1), without
+        /// touching any previously expanded sibling, so children are
+        /// iterated newest first.
+        fn push_child(&self, child: &'tree Node<'tree, S>) {
+            child.next_sibling.set(self.first_child.get());
+            self.first_child.set(Some(child));
         }
 
-        fn child_with_max_score(&self) -> Option<&Self> {
-            self.children
-                .get()
-                .iter()
-                .max_by_key(|node| node.score.get())
+        fn children(&self) -> Children<'tree, S> {
+            Children {
+                next: self.first_child.get(),
+            }
+        }
+
+        fn child_with_max_score(&self) -> Option<&'tree Self> {
+            self.children()
+                .max_by_key(|node| OrderedFloat(node.wins.get() - node.losses.get()))
+        }
+    }
+
+    /// Iterator over a node's children, walking the intrusive
+    /// `next_sibling` linked list built up by [`Node::push_child`].
+    struct Children<'tree, S: GameState> {
+        next: Option<&'tree Node<'tree, S>>,
+    }
+
+    impl<'tree, S: GameState> Iterator for Children<'tree, S> {
+        type Item = &'tree Node<'tree, S>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let node = self.next?;
+            self.next = node.next_sibling.get();
+            Some(node)
         }
     }
 
     const MAX_TRIES: u64 = 10_000;
 
+    /// How many iterations pass between checks of the clock in
+    /// [`find_next_move_timed`]. Checking on every single iteration would
+    /// make the `Instant::now()` syscall a significant fraction of the
+    /// work, so we only look once every `TIME_CHECK_INTERVAL` iterations.
+    const TIME_CHECK_INTERVAL: u64 = 128;
+
     pub fn find_next_move<S: GameState>(current_state: S, own_player: S::Player) -> S {
+        find_next_move_with_rng(
+            current_state,
+            own_player,
+            uct::SQRT_2,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`find_next_move`], but instead of running a fixed
+    /// [`MAX_TRIES`] iterations, keeps searching until `budget` has
+    /// elapsed. This mirrors the time-bounded `choose_move` design used by
+    /// competitive MCTS engines, letting the caller trade search quality
+    /// for wall-clock time instead of baking in a magic iteration count.
+    pub fn find_next_move_timed<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        budget: std::time::Duration,
+    ) -> S {
+        find_next_move_timed_with_rng(
+            current_state,
+            own_player,
+            budget,
+            uct::SQRT_2,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Like [`find_next_move`], but the RNG driving the random playouts
+    /// is seeded from `seed` instead of pulled from `thread_rng`, so two
+    /// calls with the same state, player and seed always produce the same
+    /// game. This is what makes the search deterministic and testable.
+    pub fn find_next_move_seeded<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        seed: u64,
+    ) -> S {
+        find_next_move_seeded_with_exploration(current_state, own_player, seed, uct::SQRT_2)
+    }
+
+    /// Like [`find_next_move_seeded`], but lets the caller override the
+    /// UCB1 exploration constant `C` instead of using the default
+    /// [`uct::SQRT_2`]. A higher value favors exploring less-visited
+    /// children, a lower value favors exploiting the best-looking ones.
+    pub fn find_next_move_seeded_with_exploration<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        seed: u64,
+        exploration: f64,
+    ) -> S {
+        find_next_move_with_rng(
+            current_state,
+            own_player,
+            exploration,
+            &mut StdRng::seed_from_u64(seed),
+        )
+    }
+
+    /// Output format for [`find_next_move_with_tree_dump`].
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    pub enum TreeDumpFormat {
+        /// One indented line per node: state, visit count, accumulated
+        /// wins and its UCB1 value from the final statistics.
+        Text,
+        /// A Graphviz `digraph`, one node per tree node labelled the same
+        /// way, suitable for piping into `dot -Tsvg`.
+        Dot,
+    }
+
+    /// Like [`find_next_move`], but afterwards walks the whole search tree
+    /// and writes every node's state, visit count, accumulated score and
+    /// UCB1 value to `sink` in the given `format`. This is the only way to
+    /// see *why* the AI chose a move, since the tree otherwise lives
+    /// entirely in the search's arena and is thrown away once it returns.
+    pub fn find_next_move_with_tree_dump<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        format: TreeDumpFormat,
+        sink: &mut impl std::io::Write,
+    ) -> std::io::Result<S> {
         let alloc = Bump::new();
         let opponent = own_player.next();
+        let exploration = uct::SQRT_2;
 
-        let root_node = alloc.alloc(Node::new(current_state, opponent, &alloc));
+        let root_node = alloc.alloc(Node::new(current_state, opponent, None, &alloc));
+        let mut rng = rand::thread_rng();
 
         for _ in 0..MAX_TRIES {
-            // Phase 1 - Selection
-            let promising_node = select_promising_node(root_node);
+            run_iteration(&alloc, root_node, opponent, exploration, &mut rng);
+        }
+
+        dump_tree(root_node, exploration, format, sink)?;
 
-            // Phase 2 - Expansion
-            if promising_node.state.status() == Status::InProgress {
-                expand_node(&alloc, promising_node);
+        Ok(best_child_state(root_node))
+    }
+
+    fn dump_tree<S: GameState>(
+        root_node: &Node<'_, S>,
+        exploration: f64,
+        format: TreeDumpFormat,
+        sink: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        if format == TreeDumpFormat::Dot {
+            writeln!(sink, "digraph tree {{")?;
+        }
+
+        dump_node(root_node, None, exploration, 0, format, sink)?;
+
+        if format == TreeDumpFormat::Dot {
+            writeln!(sink, "}}")?;
+        }
+
+        Ok(())
+    }
+
+    fn dump_node<S: GameState>(
+        node: &Node<'_, S>,
+        parent_visits: Option<u32>,
+        exploration: f64,
+        depth: usize,
+        format: TreeDumpFormat,
+        sink: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        let ucb = parent_visits.map(|parent_visits| {
+            uct::uct(
+                parent_visits,
+                node.wins.get(),
+                node.losses.get(),
+                node.visits.get(),
+                exploration,
+            )
+        });
+
+        match format {
+            TreeDumpFormat::Text => {
+                writeln!(
+                    sink,
+                    "{:indent$}{:?} visits={} wins={} ucb={}",
+                    "",
+                    node.state,
+                    node.visits.get(),
+                    node.wins.get(),
+                    ucb.map_or_else(|| "-".to_owned(), |ucb| format!("{ucb:.3}")),
+                    indent = depth * 2,
+                )?;
             }
+            TreeDumpFormat::Dot => {
+                let id = node as *const Node<'_, S> as usize;
+                writeln!(
+                    sink,
+                    "  n{id} [label=\"{:?}\\nvisits={} wins={} ucb={}\"];",
+                    node.state,
+                    node.visits.get(),
+                    node.wins.get(),
+                    ucb.map_or_else(|| "-".to_owned(), |ucb| format!("{ucb:.3}")),
+                )?;
+
+                if let Some(parent) = node.parent {
+                    let parent_id = parent as *const Node<'_, S> as usize;
+                    writeln!(sink, "  n{parent_id} -> n{id};")?;
+                }
+            }
+        }
 
-            // Phase 3 - Simulation
-            let promising_node = if !promising_node.children.get().is_empty() {
-                promising_node.random_child()
-            } else {
-                promising_node
-            };
-            let playout_result = simulate_random_playout(promising_node, opponent);
+        for child in node.children() {
+            dump_node(
+                child,
+                Some(node.visits.get()),
+                exploration,
+                depth + 1,
+                format,
+                sink,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Root-parallel search: builds `threads` independent trees at once,
+    /// each in its own arena with its own seeded RNG, then merges the
+    /// win/loss statistics of their root children before picking the
+    /// best one. Because every tree lives in its own thread-local `Bump`
+    /// arena, the existing `Cell`-based interior mutability on `Node`
+    /// stays sound without any synchronization between the trees.
+    pub fn find_next_move_parallel<S>(current_state: S, own_player: S::Player, threads: usize) -> S
+    where
+        S: GameState + Send,
+        S::Player: Send,
+    {
+        // At least one tree has to run, or there are no root children to
+        // merge and `merge_best_child` has nothing to pick from.
+        let threads = threads.max(1);
+
+        let per_tree_children = crossbeam::thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| {
+                    let current_state = current_state.clone();
+                    let seed: u64 = rand::random();
+
+                    scope.spawn(move |_| {
+                        let mut rng = StdRng::seed_from_u64(seed);
+                        search_tree_root_children(current_state, own_player, uct::SQRT_2, &mut rng)
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .unwrap();
+
+        merge_best_child(per_tree_children)
+    }
+
+    /// Runs a full search from scratch and returns the `(state, visits,
+    /// wins, losses)` of each of the root's children, for merging across
+    /// trees in [`find_next_move_parallel`].
+    fn search_tree_root_children<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        exploration: f64,
+        rng: &mut impl Rng,
+    ) -> Vec<(S, u32, f64, f64)> {
+        let alloc = Bump::new();
+        let opponent = own_player.next();
 
-            // Phase 4 - Update
-            back_propagation(promising_node, playout_result);
+        let root_node = alloc.alloc(Node::new(current_state, opponent, None, &alloc));
+
+        for _ in 0..MAX_TRIES {
+            run_iteration(&alloc, root_node, opponent, exploration, rng);
         }
 
+        root_node
+            .children()
+            .map(|child| {
+                (
+                    child.state.clone(),
+                    child.visits.get(),
+                    child.wins.get(),
+                    child.losses.get(),
+                )
+            })
+            .collect()
+    }
+
+    /// Sums the per-child `(wins, losses)` statistics gathered from each
+    /// tree by index and returns the state of the child with the highest
+    /// combined `wins - losses`, matching the net score
+    /// [`Node::child_with_max_score`] uses in the single-threaded search.
+    ///
+    /// Every tree explores the same root with the same lazy-expansion
+    /// order, so as long as `MAX_TRIES` is large enough for each tree to
+    /// expand every root child at least once, their children line up by
+    /// index; the `debug_assert!` below catches it if that ever stops
+    /// holding instead of silently merging the wrong states together.
+    fn merge_best_child<S: GameState>(per_tree_children: Vec<Vec<(S, u32, f64, f64)>>) -> S {
+        let mut merged: Vec<(S, f64)> = Vec::new();
+
+        for tree_children in per_tree_children {
+            for (index, (state, _visits, wins, losses)) in tree_children.into_iter().enumerate() {
+                match merged.get_mut(index) {
+                    Some((existing_state, net_score)) => {
+                        debug_assert_eq!(
+                            format!("{existing_state:?}"),
+                            format!("{state:?}"),
+                            "root children must line up by index across independently searched trees"
+                        );
+                        *net_score += wins - losses;
+                    }
+                    None => merged.push((state, wins - losses)),
+                }
+            }
+        }
+
+        merged
+            .into_iter()
+            .max_by_key(|(_, net_score)| OrderedFloat(*net_score))
+            .expect("at least one tree must have expanded a root child")
+            .0
+    }
+
+    fn find_next_move_with_rng<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        exploration: f64,
+        rng: &mut impl Rng,
+    ) -> S {
+        let alloc = Bump::new();
+        let opponent = own_player.next();
+
+        let root_node = alloc.alloc(Node::new(current_state, opponent, None, &alloc));
+
+        for _ in 0..MAX_TRIES {
+            run_iteration(&alloc, root_node, opponent, exploration, rng);
+        }
+
+        best_child_state(root_node)
+    }
+
+    fn find_next_move_timed_with_rng<S: GameState>(
+        current_state: S,
+        own_player: S::Player,
+        budget: std::time::Duration,
+        exploration: f64,
+        rng: &mut impl Rng,
+    ) -> S {
+        let start = std::time::Instant::now();
+        let alloc = Bump::new();
+        let opponent = own_player.next();
+
+        let root_node = alloc.alloc(Node::new(current_state, opponent, None, &alloc));
+
+        let mut iterations: u64 = 0;
+        loop {
+            run_iteration(&alloc, root_node, opponent, exploration, rng);
+            iterations += 1;
+
+            if iterations.is_multiple_of(TIME_CHECK_INTERVAL) && start.elapsed() >= budget {
+                break;
+            }
+        }
+
+        best_child_state(root_node)
+    }
+
+    fn run_iteration<'tree, S: GameState>(
+        alloc: &'tree Bump,
+        root_node: &'tree Node<'tree, S>,
+        opponent: S::Player,
+        exploration: f64,
+        rng: &mut impl Rng,
+    ) {
+        // Phase 1 & 2 - Selection & lazy expansion
+        let promising_node = select_and_expand(alloc, root_node, exploration);
+
+        // Phase 3 - Simulation
+        let playout_result = simulate_random_playout(promising_node, opponent, rng);
+
+        // Phase 4 - Update
+        back_propagation(promising_node, playout_result);
+    }
+
+    fn best_child_state<'tree, S: GameState>(root_node: &'tree Node<'tree, S>) -> S {
         let winner_node = root_node.child_with_max_score();
 
         let node = winner_node.unwrap();
         node.state.clone()
     }
 
-    fn select_promising_node<'tree, S: GameState>(
+    /// Walks down from `root_node`, picking the UCB1-best child at every
+    /// fully-expanded node, until it reaches a node that still has
+    /// unexplored moves (or a terminal node with no moves at all). If there
+    /// is an unexplored move, expands exactly one new child from it and
+    /// returns that child; otherwise returns the terminal node itself.
+    fn select_and_expand<'tree, S: GameState>(
+        alloc: &'tree Bump,
         root_node: &'tree Node<'tree, S>,
+        exploration: f64,
     ) -> &'tree Node<'tree, S> {
         let mut node = root_node;
 
-        while !node.children.get().is_empty() {
-            node = uct::find_best_node_with_uct(node).unwrap()
-        }
+        loop {
+            if let Some(next_state) = node.pop_unexplored() {
+                return expand_child(alloc, node, next_state);
+            }
 
-        node
+            match uct::find_best_node_with_uct(node, exploration) {
+                Some(best_child) => node = best_child,
+                None => return node,
+            }
+        }
     }
 
-    fn expand_node<'tree, S: GameState>(alloc: &'tree Bump, node: &'tree Node<'tree, S>) {
-        let possible_states = node.state.next_states();
-
-        let new_nodes = possible_states.map(|state| Node {
-            state,
-            player: node.player.next(),
-            visited: Cell::new(0),
-            score: Cell::new(0),
-            parent: Some(node),
-            children: Cell::new(alloc.alloc([])),
-        });
-
-        let children = alloc.alloc_slice_fill_iter(new_nodes);
-
-        node.children.set(children);
+    fn expand_child<'tree, S: GameState>(
+        alloc: &'tree Bump,
+        node: &'tree Node<'tree, S>,
+        state: S,
+    ) -> &'tree Node<'tree, S> {
+        let new_child = alloc.alloc(Node::new(state, node.player.next(), Some(node), alloc));
+        node.push_child(new_child);
+        new_child
     }
 
     fn back_propagation<S: GameState>(node: &Node<'_, S>, resulting_status: Status<S::Player>) {
         let mut temp_node = Some(node);
 
         while let Some(node) = temp_node {
-            node.visited.set(node.visited.get() + 1);
+            node.visits.set(node.visits.get() + 1);
 
-            if node.state.status() == resulting_status {
-                node.score.set(node.score.get() + 1);
+            match resulting_status {
+                Status::Winner(winner) if winner == node.player => {
+                    node.wins.set(node.wins.get() + 1.0);
+                }
+                Status::Winner(_) => node.losses.set(node.losses.get() + 1.0),
+                Status::Draw | Status::InProgress => {}
             }
 
             temp_node = node.parent;
@@ -153,6 +552,7 @@ mod mcts {
     fn simulate_random_playout<S: GameState>(
         node: &Node<'_, S>,
         opponent: S::Player,
+        rng: &mut impl Rng,
     ) -> Status<S::Player> {
         let mut state = node.state.clone();
 
@@ -160,14 +560,14 @@ mod mcts {
 
         if board_status == Status::Winner(opponent) {
             if let Some(parent) = node.parent {
-                parent.score.set(i32::MIN)
+                parent.wins.set(f64::NEG_INFINITY)
             }
             return board_status;
         }
 
         while board_status == Status::InProgress {
             state.toggle_player();
-            state.next_random_play();
+            state.next_random_play(rng);
             board_status = state.status();
         }
 
@@ -177,28 +577,52 @@ mod mcts {
     mod uct {
         use crate::mcts::Node;
         use crate::GameState;
-
-        pub fn uct(total_visit: u32, win_score: i32, node_visit: i32) -> u32 {
-            if node_visit == 0 {
-                return u32::MAX;
+        use ordered_float::OrderedFloat;
+
+        /// The exploration constant from the classic UCB1 formula, used
+        /// as the default unless the caller configures a different one.
+        pub const SQRT_2: f64 = std::f64::consts::SQRT_2;
+
+        /// The UCB1 score of a child with `child_visits` visits, `wins`
+        /// accumulated wins and `losses` accumulated losses, whose parent
+        /// has been visited `parent_visits` times. Draws count toward
+        /// neither `wins` nor `losses`, so a child that mostly draws scores
+        /// between one that mostly wins and one that mostly loses.
+        /// Unvisited children return `+infinity` so that every child is
+        /// tried at least once before any of them is revisited.
+        pub fn uct(
+            parent_visits: u32,
+            wins: f64,
+            losses: f64,
+            child_visits: u32,
+            exploration: f64,
+        ) -> f64 {
+            if child_visits == 0 {
+                return f64::INFINITY;
             }
 
-            let num = (win_score / node_visit) as f64
-                + std::f64::consts::SQRT_2
-                    * f64::sqrt((total_visit as f64).ln() / node_visit as f64);
+            let exploitation = (wins - losses) / child_visits as f64;
+            let exploration_term =
+                exploration * ((parent_visits as f64).ln() / child_visits as f64).sqrt();
 
-            num as u32
+            exploitation + exploration_term
         }
 
         pub(super) fn find_best_node_with_uct<'tree, S: GameState>(
             node: &'tree Node<'tree, S>,
+            exploration: f64,
         ) -> Option<&'tree Node<'tree, S>> {
-            let parent_visit_count = node.visited.get();
-
-            node.children
-                .get()
-                .iter()
-                .max_by_key(|n| uct(parent_visit_count, n.score.get(), n.score.get()))
+            let parent_visits = node.visits.get();
+
+            node.children().max_by_key(|n| {
+                OrderedFloat(uct(
+                    parent_visits,
+                    n.wins.get(),
+                    n.losses.get(),
+                    n.visits.get(),
+                    exploration,
+                ))
+            })
         }
     }
 }
@@ -330,9 +754,9 @@ pub mod tic_tac_toe {
             self.active_player = self.active_player.next();
         }
 
-        fn next_random_play(&mut self) {
+        fn next_random_play(&mut self, rng: &mut impl Rng) {
             let free_fields = self.free_fields();
-            let random_field = rand::thread_rng().gen_range(0..free_fields);
+            let random_field = rng.gen_range(0..free_fields);
 
             let (field_idx, _) = self
                 .board
@@ -365,6 +789,43 @@ pub mod tic_tac_toe {
         }
     }
 
+    #[cfg(test)]
+    mod tests {
+        use super::{Board, Player, State};
+        use crate::{find_next_move_seeded, GameState, Status};
+
+        #[test]
+        fn seeded_search_is_deterministic() {
+            let board = Board::new(Player::O);
+
+            let first = find_next_move_seeded(board, Player::X, 42);
+            let second = find_next_move_seeded(board, Player::X, 42);
+
+            assert_eq!(format!("{first:?}"), format!("{second:?}"));
+        }
+
+        #[test]
+        fn ai_takes_the_winning_move() {
+            // X X _
+            // O O X
+            // X O _
+            // X has two in a row at 0/1 with 2 the only way to complete it;
+            // the other empty field (8) doesn't let X win immediately.
+            let mut board = Board::new(Player::O);
+            board.board[0] = State::X;
+            board.board[1] = State::X;
+            board.board[3] = State::O;
+            board.board[4] = State::O;
+            board.board[5] = State::X;
+            board.board[6] = State::X;
+            board.board[7] = State::O;
+
+            let result = find_next_move_seeded(board, Player::X, 42);
+
+            assert_eq!(result.status(), Status::Winner(Player::X));
+        }
+    }
+
     pub use run::main;
 
     mod run {